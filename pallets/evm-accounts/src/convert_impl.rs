@@ -0,0 +1,41 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Converts an `EvmAddress` into the `AccountId` it is linked to.
+///
+/// Prefers the explicit mapping created by `claim_account`/genesis and falls
+/// back to the deterministic, padded-address account derived by
+/// `T::OriginAddressMapping` when no explicit link exists.
+pub struct EVMAddressToAccountId<T>(PhantomData<T>);
+
+impl<T: Config> Convert<EvmAddress, T::AccountId> for EVMAddressToAccountId<T> {
+	fn convert(address: EvmAddress) -> T::AccountId {
+		Accounts::<T>::get(address).unwrap_or_else(|| T::OriginAddressMapping::into_account_id(address))
+	}
+}
+
+/// Converts an `AccountId` into its linked `EvmAddress`, if any.
+pub struct AccountIdToEVMAddress<T>(PhantomData<T>);
+
+impl<T: Config> Convert<T::AccountId, Option<EvmAddress>> for AccountIdToEVMAddress<T> {
+	fn convert(account_id: T::AccountId) -> Option<EvmAddress> {
+		EvmAddresses::<T>::get(account_id)
+	}
+}
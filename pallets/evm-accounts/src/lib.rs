@@ -42,7 +42,10 @@ use precompile_utils::prelude::keccak256;
 use peaq_primitives_xcm::{evm::EvmAddress, to_bytes};
 use sp_core::{crypto::AccountId32, H160, H256};
 use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
-use sp_runtime::traits::{Convert, Zero};
+use sp_runtime::{
+	traits::{Convert, LookupError, StaticLookup, Zero},
+	MultiAddress,
+};
 use sp_std::{marker::PhantomData, vec::Vec};
 
 mod convert_impl;
@@ -53,7 +56,7 @@ pub mod weights;
 
 use convert_impl::*;
 pub use module::*;
-pub use traits::EVMAddressMapping;
+pub use traits::{ContractCodeInspector, EVMAddressMapping};
 pub use weights::WeightInfo;
 
 /// A signature (a 512-bit value, plus 8 bits for recovery ID).
@@ -83,6 +86,10 @@ pub mod module {
 		#[pallet::constant]
 		type ChainId: Get<u64>;
 
+		/// Inspects whether an `eth_address` has contract code deployed, so contract
+		/// addresses can be rejected from `claim_account` (EIP-3607).
+		type ContractCodeInspector: ContractCodeInspector;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -93,6 +100,9 @@ pub mod module {
 		/// Mapping between Substrate accounts and EVM accounts
 		/// claim account.
 		ClaimAccount { account_id: T::AccountId, evm_address: EvmAddress },
+		/// Mapping between Substrate accounts and EVM accounts
+		/// removed.
+		UnlinkAccount { account_id: T::AccountId, evm_address: EvmAddress },
 	}
 
 	/// Error for evm accounts module.
@@ -108,6 +118,10 @@ pub mod module {
 		InvalidSignature,
 		/// Account ref count is not zero
 		NonZeroRefCount,
+		/// The eth_address is a contract address and cannot be claimed (EIP-3607)
+		ContractAddressNotClaimable,
+		/// AccountId has not mapped
+		AccountIdNotMapped,
 	}
 
 	/// The Substrate Account for EvmAddresses
@@ -129,6 +143,39 @@ pub mod module {
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// Pre-linked Substrate <-> EVM account pairs to seed at genesis, mirroring how
+	/// Frontier templates ship well-known development accounts already linked.
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub accounts: Vec<(T::AccountId, EvmAddress)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { accounts: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (account_id, eth_address) in &self.accounts {
+				assert!(
+					!EvmAddresses::<T>::contains_key(account_id),
+					"EvmAccounts genesis error: AccountId has mapped"
+				);
+				assert!(
+					!Accounts::<T>::contains_key(eth_address),
+					"EvmAccounts genesis error: Eth address has mapped"
+				);
+
+				Accounts::<T>::insert(eth_address, account_id);
+				EvmAddresses::<T>::insert(account_id, eth_address);
+			}
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
 
@@ -150,26 +197,60 @@ pub mod module {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// ensure account_id and eth_address has not been mapped
-			ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
-			ensure!(!Accounts::<T>::contains_key(eth_address), Error::<T>::EthAddressHasMapped);
-
 			// recover evm address from signature
 			let address = Self::verify_eip712_signature(&who, &eth_signature)
 				.ok_or(Error::<T>::BadSignature)?;
 			ensure!(eth_address == address, Error::<T>::InvalidSignature);
 
-			let account_id = T::OriginAddressMapping::into_account_id(eth_address);
-			if frame_system::Pallet::<T>::account_exists(&account_id) {
-				// merge balance from `evm padded address` to `origin`
-				let amount = T::Currency::reducible_balance(&account_id, false);
-				T::Currency::transfer(&account_id, &who, amount, ExistenceRequirement::AllowDeath)?;
-			}
+			Self::do_link(who, eth_address)
+		}
+
+		/// Claim account mapping between Substrate accounts and EVM accounts using an
+		/// EIP-191 `personal_sign` signature instead of EIP-712 structured data, for
+		/// wallets that can only produce `\x19Ethereum Signed Message:\n`-prefixed
+		/// signatures.
+		///
+		/// - `eth_signature`: A `personal_sign` signature over the message returned by
+		///   `personal_sign_signable_message`, proving ownership of the signing address
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::claim_account_personal_sign())]
+		#[transactional]
+		pub fn claim_account_personal_sign(
+			origin: OriginFor<T>,
+			eth_signature: Eip712Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			Accounts::<T>::insert(eth_address, &who);
-			EvmAddresses::<T>::insert(&who, eth_address);
+			// recover evm address from signature
+			let eth_address =
+				Self::verify_personal_sign_signature(&who, &eth_signature).ok_or(Error::<T>::BadSignature)?;
 
-			Self::deposit_event(Event::ClaimAccount { account_id: who, evm_address: eth_address });
+			Self::do_link(who, eth_address)
+		}
+
+		/// Unlink the caller's Substrate account from its linked EVM address.
+		///
+		/// Fails with `NonZeroRefCount` if the padded-address account derived from the
+		/// linked `eth_address` still has consumer references (and therefore may hold locked
+		/// balance), since unlinking would leave it unreachable.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::unlink_account())]
+		#[transactional]
+		pub fn unlink_account(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let eth_address = EvmAddresses::<T>::get(&who).ok_or(Error::<T>::AccountIdNotMapped)?;
+
+			let padded_account = T::OriginAddressMapping::into_account_id(eth_address);
+			ensure!(
+				frame_system::Pallet::<T>::consumers(&padded_account) == 0,
+				Error::<T>::NonZeroRefCount
+			);
+
+			Accounts::<T>::remove(eth_address);
+			EvmAddresses::<T>::remove(&who);
+
+			Self::deposit_event(Event::UnlinkAccount { account_id: who, evm_address: eth_address });
 
 			Ok(())
 		}
@@ -208,6 +289,80 @@ impl<T: Config> Pallet<T> {
 		recover_signer(sig, &msg_hash)
 	}
 
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Constructs the personal_sign message and signs it.
+	// Only for tests
+	pub fn personal_sign(secret: &libsecp256k1::SecretKey, who: &T::AccountId) -> Eip712Signature {
+		let msg_hash = keccak_256(&Self::personal_sign_signable_message(who));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg_hash), secret);
+		let mut r = [0u8; 65];
+		r[0..64].copy_from_slice(&sig.serialize()[..]);
+		r[64] = recovery_id.serialize();
+		r
+	}
+
+	fn verify_personal_sign_signature(who: &T::AccountId, sig: &[u8; 65]) -> Option<H160> {
+		let msg_hash = keccak_256(&Self::personal_sign_signable_message(who));
+
+		recover_signer(sig, &msg_hash)
+	}
+
+	// EIP-191 `personal_sign` message to be signed: the EIP-191 prefix plus the
+	// ASCII-encoded length of `body` plus `body` itself.
+	fn personal_sign_signable_message(who: &T::AccountId) -> Vec<u8> {
+		let body = Self::personal_sign_body(who);
+
+		let mut msg = b"\x19Ethereum Signed Message:\n".to_vec();
+		msg.extend_from_slice(&to_decimal(body.len() as u64));
+		msg.extend_from_slice(&body);
+		msg
+	}
+
+	// Human-readable claim message. Embeds the genesis block hash and chain id so the
+	// signature cannot be replayed on another chain, and the SCALE-encoded account so it
+	// cannot be replayed for a different Substrate account.
+	fn personal_sign_body(who: &T::AccountId) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+
+		let mut body = b"Link Peaq account\nGenesis: 0x".to_vec();
+		body.extend_from_slice(&to_hex(genesis_hash.as_ref()));
+		body.extend_from_slice(b"\nChain ID: ");
+		body.extend_from_slice(&to_decimal(T::ChainId::get()));
+		body.extend_from_slice(b"\nAccount: 0x");
+		body.extend_from_slice(&to_hex(&who.encode()));
+		body
+	}
+
+	/// Shared post-verification bookkeeping for `claim_account` and
+	/// `claim_account_personal_sign`: duplicate checks, the EIP-3607 contract-code
+	/// guard, balance merge from the padded address, storage inserts and the event.
+	#[transactional]
+	fn do_link(who: T::AccountId, eth_address: EvmAddress) -> DispatchResult {
+		// ensure account_id and eth_address has not been mapped
+		ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
+		ensure!(!Accounts::<T>::contains_key(eth_address), Error::<T>::EthAddressHasMapped);
+		// reject contract addresses: they have no private key, so a valid signature
+		// over them implies a hash collision or misuse (EIP-3607)
+		ensure!(
+			!T::ContractCodeInspector::is_contract(&eth_address),
+			Error::<T>::ContractAddressNotClaimable
+		);
+
+		let account_id = T::OriginAddressMapping::into_account_id(eth_address);
+		if frame_system::Pallet::<T>::account_exists(&account_id) {
+			// merge balance from `evm padded address` to `origin`
+			let amount = T::Currency::reducible_balance(&account_id, false);
+			T::Currency::transfer(&account_id, &who, amount, ExistenceRequirement::AllowDeath)?;
+		}
+
+		Accounts::<T>::insert(eth_address, &who);
+		EvmAddresses::<T>::insert(&who, eth_address);
+
+		Self::deposit_event(Event::ClaimAccount { account_id: who, evm_address: eth_address });
+
+		Ok(())
+	}
+
 	// Eip-712 message to be signed
 	fn eip712_signable_message(who: &T::AccountId) -> Vec<u8> {
 		let domain_separator = Self::evm_account_domain_separator();
@@ -240,6 +395,31 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+// Lower-case hex encoding, without a `0x` prefix.
+fn to_hex(data: &[u8]) -> Vec<u8> {
+	const CHARS: &[u8; 16] = b"0123456789abcdef";
+	let mut s = Vec::with_capacity(data.len() * 2);
+	for b in data {
+		s.push(CHARS[(b >> 4) as usize]);
+		s.push(CHARS[(b & 0x0f) as usize]);
+	}
+	s
+}
+
+// ASCII decimal encoding.
+fn to_decimal(mut n: u64) -> Vec<u8> {
+	if n == 0 {
+		return b"0".to_vec();
+	}
+	let mut buf = Vec::new();
+	while n > 0 {
+		buf.push(b'0' + (n % 10) as u8);
+		n /= 10;
+	}
+	buf.reverse();
+	buf
+}
+
 fn recover_signer(sig: &[u8; 65], msg_hash: &[u8; 32]) -> Option<H160> {
 	secp256k1_ecdsa_recover(sig, msg_hash)
 		.map(|pubkey| H160::from(H256::from_slice(&keccak_256(&pubkey))))
@@ -293,22 +473,23 @@ impl<T: Config> OnKilledAccount<T::AccountId> for CallKillEVMLinkAccount<T> {
 	}
 }
 
-/*
- * // TODO, Need to survey
- * // I guess it is related to the address unification, but let us survey it later
- * impl<T: Config> StaticLookup for Pallet<T> {
- *     type Source = MultiAddress<T::AccountId, AccountIndex>;
- *     type Target = T::AccountId;
- *
- *     fn lookup(a: Self::Source) -> Result<Self::Target, LookupError> {
- *         match a {
- *             MultiAddress::Address20(i) =>
- * Ok(T::AddressMapping::get_account_id(&EvmAddress::from_slice(&i))),             _ =>
- * Err(LookupError),         }
- *     }
- *
- *     fn unlookup(a: Self::Target) -> Self::Source {
- *         MultiAddress::Id(a)
- *     }
- * }
- */
+/// Resolves a `MultiAddress::Address20(i)` source to the Substrate account actually
+/// linked through `claim_account` (or the deterministic padded-address account when
+/// no explicit link exists), so other pallets that accept `T::Lookup` can address a
+/// user by their Ethereum address.
+impl<T: Config> StaticLookup for Pallet<T> {
+	type Source = MultiAddress<T::AccountId, ()>;
+	type Target = T::AccountId;
+
+	fn lookup(a: Self::Source) -> Result<Self::Target, LookupError> {
+		match a {
+			MultiAddress::Address20(i) => Ok(EVMAddressToAccountId::<T>::convert(EvmAddress::from_slice(&i))),
+			MultiAddress::Id(id) => Ok(id),
+			_ => Err(LookupError),
+		}
+	}
+
+	fn unlookup(a: Self::Target) -> Self::Source {
+		MultiAddress::Id(a)
+	}
+}
@@ -0,0 +1,165 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+
+/// An EVM address that the mock treats as a deployed contract.
+pub const CONTRACT_ADDRESS: EvmAddress = EvmAddress::repeat_byte(0xcc);
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+parameter_types! {
+	pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = CallKillEVMLinkAccount<Test>;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+pub struct MockAddressMapping;
+impl PalletEVMAddressMapping<AccountId> for MockAddressMapping {
+	fn into_account_id(address: H160) -> AccountId {
+		let mut data = [0u8; 32];
+		data[0..4].copy_from_slice(b"evm:");
+		data[4..24].copy_from_slice(&address[..]);
+		AccountId32::from(data)
+	}
+}
+
+std::thread_local! {
+	static CONTRACT_ADDRESSES: std::cell::RefCell<sp_std::vec::Vec<EvmAddress>> =
+		std::cell::RefCell::new(sp_std::vec![CONTRACT_ADDRESS]);
+}
+
+pub struct MockContractCodeInspector;
+impl ContractCodeInspector for MockContractCodeInspector {
+	fn is_contract(address: &EvmAddress) -> bool {
+		CONTRACT_ADDRESSES.with(|c| c.borrow().contains(address))
+	}
+}
+
+impl MockContractCodeInspector {
+	/// Marks `address` as a deployed contract for the remainder of the test.
+	pub fn mark_as_contract(address: EvmAddress) {
+		CONTRACT_ADDRESSES.with(|c| c.borrow_mut().push(address));
+	}
+}
+
+parameter_types! {
+	pub const ChainId: u64 = 595;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type OriginAddressMapping = MockAddressMapping;
+	type ChainId = ChainId;
+	type ContractCodeInspector = MockContractCodeInspector;
+	type WeightInfo = ();
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		EvmAccounts: module::{Pallet, Call, Storage, Config<T>, Event<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	linked_accounts: Vec<(AccountId, EvmAddress)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder { linked_accounts: Default::default() }
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_linked_accounts(mut self, accounts: Vec<(AccountId, EvmAddress)>) -> Self {
+		self.linked_accounts = accounts;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		module::GenesisConfig::<Test> { accounts: self.linked_accounts }
+			.assimilate_storage(&mut t)
+			.unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
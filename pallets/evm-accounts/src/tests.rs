@@ -0,0 +1,208 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Test, *};
+
+fn alice_key() -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&[1u8; 32]).unwrap()
+}
+
+#[test]
+fn claim_account_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = alice_key();
+		let eth_address = Pallet::<Test>::eth_address(&secret);
+		let signature = Pallet::<Test>::eth_sign(&secret, &ALICE);
+
+		assert_ok!(EvmAccounts::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			eth_address,
+			signature
+		));
+		assert_eq!(Accounts::<Test>::get(eth_address), Some(ALICE));
+		assert_eq!(EvmAddresses::<Test>::get(ALICE), Some(eth_address));
+	});
+}
+
+#[test]
+fn claim_account_rejects_contract_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = alice_key();
+		let eth_address = Pallet::<Test>::eth_address(&secret);
+		let signature = Pallet::<Test>::eth_sign(&secret, &ALICE);
+		MockContractCodeInspector::mark_as_contract(eth_address);
+
+		assert_noop!(
+			EvmAccounts::claim_account(RuntimeOrigin::signed(ALICE), eth_address, signature),
+			Error::<Test>::ContractAddressNotClaimable
+		);
+	});
+}
+
+#[test]
+fn lookup_resolves_explicitly_claimed_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = alice_key();
+		let eth_address = Pallet::<Test>::eth_address(&secret);
+		let signature = Pallet::<Test>::eth_sign(&secret, &ALICE);
+
+		assert_ok!(EvmAccounts::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			eth_address,
+			signature
+		));
+
+		let resolved =
+			<Pallet<Test> as sp_runtime::traits::StaticLookup>::lookup(MultiAddress::Address20(eth_address.0))
+				.unwrap();
+		assert_eq!(resolved, ALICE);
+	});
+}
+
+#[test]
+fn lookup_falls_back_to_derived_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let eth_address = EvmAddress::repeat_byte(0xaa);
+		let resolved =
+			<Pallet<Test> as sp_runtime::traits::StaticLookup>::lookup(MultiAddress::Address20(eth_address.0))
+				.unwrap();
+		assert_eq!(resolved, MockAddressMapping::into_account_id(eth_address));
+	});
+}
+
+#[test]
+fn lookup_rejects_unsupported_variants() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			<Pallet<Test> as sp_runtime::traits::StaticLookup>::lookup(MultiAddress::Index(())),
+			sp_runtime::traits::LookupError
+		);
+	});
+}
+
+fn bob_key() -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&[2u8; 32]).unwrap()
+}
+
+#[test]
+fn unlink_account_then_claim_with_new_address_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = alice_key();
+		let eth_address = Pallet::<Test>::eth_address(&secret);
+		let signature = Pallet::<Test>::eth_sign(&secret, &ALICE);
+		assert_ok!(EvmAccounts::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			eth_address,
+			signature
+		));
+
+		assert_ok!(EvmAccounts::unlink_account(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Accounts::<Test>::get(eth_address), None);
+		assert_eq!(EvmAddresses::<Test>::get(ALICE), None);
+
+		let new_secret = bob_key();
+		let new_eth_address = Pallet::<Test>::eth_address(&new_secret);
+		let new_signature = Pallet::<Test>::eth_sign(&new_secret, &ALICE);
+		assert_ok!(EvmAccounts::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			new_eth_address,
+			new_signature
+		));
+		assert_eq!(Accounts::<Test>::get(new_eth_address), Some(ALICE));
+		assert_eq!(EvmAddresses::<Test>::get(ALICE), Some(new_eth_address));
+	});
+}
+
+#[test]
+fn unlink_account_fails_when_not_linked() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmAccounts::unlink_account(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::AccountIdNotMapped
+		);
+	});
+}
+
+#[test]
+fn genesis_build_seeds_linked_accounts() {
+	let eth_address = EvmAddress::repeat_byte(0xbb);
+	ExtBuilder::default()
+		.with_linked_accounts(sp_std::vec![(ALICE, eth_address)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Accounts::<Test>::get(eth_address), Some(ALICE));
+			assert_eq!(EvmAddresses::<Test>::get(ALICE), Some(eth_address));
+		});
+}
+
+#[test]
+#[should_panic(expected = "AccountId has mapped")]
+fn genesis_build_panics_on_duplicate_account_id() {
+	let eth_address_1 = EvmAddress::repeat_byte(0xb1);
+	let eth_address_2 = EvmAddress::repeat_byte(0xb2);
+	ExtBuilder::default()
+		.with_linked_accounts(sp_std::vec![(ALICE, eth_address_1), (ALICE, eth_address_2)])
+		.build();
+}
+
+#[test]
+#[should_panic(expected = "Eth address has mapped")]
+fn genesis_build_panics_on_duplicate_eth_address() {
+	let eth_address = EvmAddress::repeat_byte(0xb3);
+	ExtBuilder::default()
+		.with_linked_accounts(sp_std::vec![(ALICE, eth_address), (BOB, eth_address)])
+		.build();
+}
+
+#[test]
+fn claim_account_personal_sign_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = alice_key();
+		let signature = Pallet::<Test>::personal_sign(&secret, &ALICE);
+
+		assert_ok!(EvmAccounts::claim_account_personal_sign(
+			RuntimeOrigin::signed(ALICE),
+			signature
+		));
+
+		let eth_address = Pallet::<Test>::eth_address(&secret);
+		assert_eq!(Accounts::<Test>::get(eth_address), Some(ALICE));
+		assert_eq!(EvmAddresses::<Test>::get(ALICE), Some(eth_address));
+	});
+}
+
+#[test]
+fn claim_account_personal_sign_is_bound_to_the_signed_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = alice_key();
+		let signature = Pallet::<Test>::personal_sign(&secret, &ALICE);
+		let real_eth_address = Pallet::<Test>::eth_address(&secret);
+
+		// the preimage embeds ALICE's account, so replaying the signature under BOB's
+		// origin does not link BOB to the signer's real EVM address
+		assert_ok!(EvmAccounts::claim_account_personal_sign(
+			RuntimeOrigin::signed(BOB),
+			signature
+		));
+		assert_ne!(EvmAddresses::<Test>::get(BOB), Some(real_eth_address));
+	});
+}
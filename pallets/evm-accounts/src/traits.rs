@@ -0,0 +1,42 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use peaq_primitives_xcm::evm::EvmAddress;
+
+/// A mapping between AccountId and EVM 160-bit address.
+pub trait EVMAddressMapping<AccountId> {
+	/// Returns the AccountId used to generate the given EvmAddress.
+	fn get_account_id(address: &EvmAddress) -> AccountId;
+	/// Returns the EvmAddress explicitly linked to a given AccountId.
+	/// Returns None if there is no such link; an EvmAddress embedded in a
+	/// padded-address AccountId is not derived.
+	fn get_evm_address(account_id: &AccountId) -> Option<EvmAddress>;
+	/// Returns true if a given AccountId is associated with a given EvmAddress
+	/// and false if is not.
+	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool;
+}
+
+/// Inspects whether a given EVM address currently has contract code deployed.
+///
+/// Used to guard `claim_account` against EIP-3607: an address controlled by
+/// contract bytecode has no private key, so binding it to a Substrate account
+/// would either imply a hash collision or a misuse of the claim flow.
+pub trait ContractCodeInspector {
+	/// Returns `true` if `address` has non-empty contract code.
+	fn is_contract(address: &EvmAddress) -> bool;
+}